@@ -43,13 +43,17 @@ impl Mul<f64> for FreqUnit {
     }
 }
 
-/// Represents a frequency band
-#[derive(PartialEq, Debug)]
+/// Represents a frequency band. The sweep points are always kept in Hz;
+/// `unit` only records the unit the band was specified/read in. It does not
+/// rescale the stored points, and `Network::to_snp` does not use it: that
+/// writer always emits frequencies in Hz.
+#[derive(Clone, PartialEq, Debug)]
 pub struct Frequency {
     f: Array1<f64>,
     start: f64,
     stop: f64,
     npoints: usize,
+    unit: FreqUnit,
 }
 
 impl From<Vec<f64>> for Frequency {
@@ -60,11 +64,26 @@ impl From<Vec<f64>> for Frequency {
             start: freqs[0],
             stop: freqs.last().cloned().unwrap(),
             npoints: freqs.len(),
+            unit: FreqUnit::Hz,
         }
     }
 }
 
 impl Frequency {
+    /// Builds a frequency band from a column of raw (unscaled) frequency
+    /// values and the unit they were specified in, e.g. a Touchstone file's
+    /// frequency column under its `options.unit`. Unlike [`Frequency::new`],
+    /// the points need not be evenly spaced.
+    pub(crate) fn from_raw(freqs: &[f64], unit: FreqUnit) -> Self {
+        Frequency {
+            f: Array::from_vec(freqs.iter().map(|&v| unit * v).collect()),
+            start: unit * freqs[0],
+            stop: unit * freqs.last().cloned().unwrap(),
+            npoints: freqs.len(),
+            unit,
+        }
+    }
+
     pub fn new(start: f64, stop: f64, npoints: Option<usize>, unit: Option<FreqUnit>) -> Self {
         let n = match npoints {
             Some(n) => n,
@@ -80,8 +99,29 @@ impl Frequency {
             start,
             stop,
             npoints: n,
+            unit,
         }
     }
+
+    /// The sweep points in Hz.
+    pub fn as_hz(&self) -> Array1<f64> {
+        self.f.clone()
+    }
+
+    /// The unit this frequency band was specified/read in.
+    pub fn unit(&self) -> FreqUnit {
+        self.unit
+    }
+
+    /// The midpoint of the sweep, in Hz.
+    pub fn center(&self) -> f64 {
+        (self.f[0] + self.f[self.f.len() - 1]) / 2.
+    }
+
+    /// The width of the sweep, in Hz.
+    pub fn span(&self) -> f64 {
+        self.f[self.f.len() - 1] - self.f[0]
+    }
 }
 
 #[cfg(test)]
@@ -96,8 +136,16 @@ mod test {
             start: 0.,
             stop: 5.,
             npoints: 6,
+            unit: FreqUnit::Hz,
         };
         let test = Frequency::new(0., 5., Some(6), Some(FreqUnit::Hz));
         assert_eq!(test, good);
     }
+
+    #[test]
+    fn test_center_and_span() {
+        let freq = Frequency::new(1., 5., Some(5), Some(FreqUnit::Hz));
+        assert_eq!(freq.center(), 3.);
+        assert_eq!(freq.span(), 4.);
+    }
 }
@@ -0,0 +1,240 @@
+//! Composing networks: cascading two-ports end-to-end via wave-transfer (T)
+//! parameters, and connecting an arbitrary inner port pair of two
+//! multiport networks together.
+
+use std::ops::Mul;
+
+use ndarray::array;
+use ndarray::prelude::*;
+use num::complex::Complex;
+
+use super::Network;
+
+/// Converts a two-port S-matrix to its wave-transfer (T) equivalent.
+fn s_to_t(s: ArrayView2<Complex<f64>>) -> Array2<Complex<f64>> {
+    let s11 = s[[0, 0]];
+    let s12 = s[[0, 1]];
+    let s21 = s[[1, 0]];
+    let s22 = s[[1, 1]];
+    let det_s = s11 * s22 - s12 * s21;
+
+    array![
+        [-det_s / s21, s11 / s21],
+        [-s22 / s21, Complex::new(1., 0.) / s21],
+    ]
+}
+
+/// Converts a two-port T-matrix back to S.
+fn t_to_s(t: &Array2<Complex<f64>>) -> Array2<Complex<f64>> {
+    let t11 = t[[0, 0]];
+    let t12 = t[[0, 1]];
+    let t21 = t[[1, 0]];
+    let t22 = t[[1, 1]];
+    let det_t = t11 * t22 - t12 * t21;
+
+    array![
+        [t12 / t22, det_t / t22],
+        [Complex::new(1., 0.) / t22, -t21 / t22],
+    ]
+}
+
+impl Network {
+    /// Cascades two two-port networks end to end: each frequency's
+    /// S-matrix is converted to T-parameters, multiplied, then converted
+    /// back. The two networks must share a compatible `Frequency` and
+    /// matching `z0`.
+    pub fn cascade(&self, other: &Network) -> Network {
+        assert_eq!(self.s.dim().1, 2, "cascade only supports two-port networks");
+        assert_eq!(other.s.dim().1, 2, "cascade only supports two-port networks");
+        assert_eq!(
+            self.f.as_hz(),
+            other.f.as_hz(),
+            "networks must share a compatible frequency"
+        );
+        assert_eq!(self.z0, other.z0, "networks must share matching z0");
+
+        let nfreq = self.s.dim().0;
+        let mut s = Array3::<Complex<f64>>::zeros((nfreq, 2, 2));
+        for k in 0..nfreq {
+            let ta = s_to_t(self.s.slice(s![k, .., ..]));
+            let tb = s_to_t(other.s.slice(s![k, .., ..]));
+            let s_k = t_to_s(&ta.dot(&tb));
+            s.slice_mut(s![k, .., ..]).assign(&s_k);
+        }
+        Network::new(self.f.clone(), s, self.z0.clone())
+    }
+}
+
+impl Mul for &Network {
+    type Output = Network;
+
+    fn mul(self, other: &Network) -> Network {
+        self.cascade(other)
+    }
+}
+
+/// Builds the block-diagonal S-matrix of two networks taken together, with
+/// no ports connected.
+fn block_diag(a: ArrayView2<Complex<f64>>, b: ArrayView2<Complex<f64>>) -> Array2<Complex<f64>> {
+    let na = a.nrows();
+    let nb = b.nrows();
+    let mut m = Array2::<Complex<f64>>::zeros((na + nb, na + nb));
+    m.slice_mut(s![..na, ..na]).assign(&a);
+    m.slice_mut(s![na.., na..]).assign(&b);
+    m
+}
+
+/// Eliminates ports `p` and `q` of `s` by connecting them to each other
+/// (the wave leaving one enters the other), updating every remaining
+/// port pair. Derived by writing `a_p`/`a_q` as a 2x2 linear system in the
+/// other ports' incident waves and substituting back into `b_i = S_ij a_j`.
+/// Entries involving `p` or `q` are left untouched and unused by the
+/// caller, which only reads the remaining rows/columns.
+fn eliminate_ports(s: &Array2<Complex<f64>>, p: usize, q: usize) -> Array2<Complex<f64>> {
+    let n = s.nrows();
+    let one = Complex::new(1., 0.);
+
+    let det = (one - s[[q, p]]) * (one - s[[p, q]]) - s[[q, q]] * s[[p, p]];
+    let m_inv = [
+        [(one - s[[p, q]]) / det, s[[q, q]] / det],
+        [s[[p, p]] / det, (one - s[[q, p]]) / det],
+    ];
+
+    let mut out = s.clone();
+    for i in 0..n {
+        for j in 0..n {
+            if i == p || i == q || j == p || j == q {
+                continue;
+            }
+            let r_q = s[[q, j]];
+            let r_p = s[[p, j]];
+            let a_p = m_inv[0][0] * r_q + m_inv[0][1] * r_p;
+            let a_q = m_inv[1][0] * r_q + m_inv[1][1] * r_p;
+            out[[i, j]] = s[[i, j]] + s[[i, p]] * a_p + s[[i, q]] * a_q;
+        }
+    }
+    out
+}
+
+/// Connects port `port_a` of `net_a` to port `port_b` of `net_b`, returning
+/// a network over the remaining ports: `net_a`'s ports other than
+/// `port_a`, followed by `net_b`'s ports other than `port_b`. Lets T-lines
+/// and n-ports be chained at an arbitrary inner port, not just end to end.
+pub fn connect(net_a: &Network, port_a: usize, net_b: &Network, port_b: usize) -> Network {
+    assert_eq!(
+        net_a.f.as_hz(),
+        net_b.f.as_hz(),
+        "networks must share a compatible frequency"
+    );
+    assert_eq!(
+        net_a.z0.slice(s![port_a, ..]),
+        net_b.z0.slice(s![port_b, ..]),
+        "connected ports must share the same reference impedance"
+    );
+
+    let na = net_a.s.dim().1;
+    let nb = net_b.s.dim().1;
+    let nfreq = net_a.s.dim().0;
+    let p = port_a;
+    let q = na + port_b;
+    let remaining: Vec<usize> = (0..na + nb).filter(|&i| i != p && i != q).collect();
+
+    let mut z0 = Array2::<Complex<f64>>::zeros((remaining.len(), nfreq));
+    for (row, &port) in remaining.iter().enumerate() {
+        for k in 0..nfreq {
+            z0[[row, k]] = if port < na {
+                net_a.z0[[port, k]]
+            } else {
+                net_b.z0[[port - na, k]]
+            };
+        }
+    }
+
+    let mut s = Array3::<Complex<f64>>::zeros((nfreq, remaining.len(), remaining.len()));
+    for k in 0..nfreq {
+        let combined = block_diag(net_a.s.slice(s![k, .., ..]), net_b.s.slice(s![k, .., ..]));
+        let reduced = eliminate_ports(&combined, p, q);
+        for (i, &ri) in remaining.iter().enumerate() {
+            for (j, &rj) in remaining.iter().enumerate() {
+                s[[k, i, j]] = reduced[[ri, rj]];
+            }
+        }
+    }
+
+    Network::new(net_a.f.clone(), s, z0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::frequency::{FreqUnit, Frequency};
+
+    fn two_port(s11: Complex<f64>, s12: Complex<f64>, s21: Complex<f64>, s22: Complex<f64>) -> Network {
+        let freq = Frequency::new(1., 1., Some(1), Some(FreqUnit::GHz));
+        let s = array![[[s11, s12], [s21, s22]]];
+        let z0 = Array::from_elem((2, 1), Complex::new(50., 0.));
+        Network::new(freq, s, z0)
+    }
+
+    #[test]
+    fn test_cascade_matches_connect() {
+        let a = two_port(
+            Complex::new(0.1, 0.02),
+            Complex::new(0.85, -0.05),
+            Complex::new(0.85, -0.05),
+            Complex::new(0.15, 0.03),
+        );
+        let b = two_port(
+            Complex::new(0.2, -0.01),
+            Complex::new(0.75, 0.1),
+            Complex::new(0.75, 0.1),
+            Complex::new(0.1, -0.02),
+        );
+
+        let cascaded = a.cascade(&b);
+        let connected = connect(&a, 1, &b, 0);
+
+        for i in 0..2 {
+            for j in 0..2 {
+                let diff = (cascaded.s[[0, i, j]] - connected.s[[0, i, j]]).norm();
+                assert!(diff < 1e-9, "mismatch at ({}, {}): {}", i, j, diff);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "connected ports must share the same reference impedance")]
+    fn test_connect_rejects_mismatched_z0() {
+        let freq = Frequency::new(1., 1., Some(1), Some(FreqUnit::GHz));
+        let a = two_port(
+            Complex::new(0.1, 0.02),
+            Complex::new(0.85, -0.05),
+            Complex::new(0.85, -0.05),
+            Complex::new(0.15, 0.03),
+        );
+        let s = array![[
+            [Complex::new(0.2, -0.01), Complex::new(0.75, 0.1)],
+            [Complex::new(0.75, 0.1), Complex::new(0.1, -0.02)],
+        ]];
+        let z0 = Array::from_elem((2, 1), Complex::new(75., 0.));
+        let b = Network::new(freq, s, z0);
+
+        connect(&a, 1, &b, 0);
+    }
+
+    #[test]
+    fn test_cascade_allows_different_units_same_hz() {
+        // One network's sweep is labeled GHz, the other MHz, but both
+        // describe the same 1 GHz point in Hz -- `cascade` should compare
+        // absolute frequency, not the display unit.
+        let s = array![[
+            [Complex::new(0.1, 0.02), Complex::new(0.85, -0.05)],
+            [Complex::new(0.85, -0.05), Complex::new(0.15, 0.03)],
+        ]];
+        let z0 = Array::from_elem((2, 1), Complex::new(50., 0.));
+        let a = Network::new(Frequency::new(1., 1., Some(1), Some(FreqUnit::GHz)), s.clone(), z0.clone());
+        let b = Network::new(Frequency::new(1000., 1000., Some(1), Some(FreqUnit::MHz)), s, z0);
+
+        let _ = a.cascade(&b);
+    }
+}
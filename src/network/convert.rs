@@ -0,0 +1,220 @@
+//! Conversions between the S, Z, Y and ABCD network parameter
+//! representations. `Network` is S-internal; these routines convert a
+//! caller's parameters into S for the constructors, or the stored S matrix
+//! into another representation for the accessors, one frequency point at a
+//! time.
+
+use ndarray::prelude::*;
+use num::complex::Complex;
+
+use crate::frequency::Frequency;
+
+use super::Network;
+
+/// Builds a diagonal matrix from a vector of per-port values.
+pub(crate) fn diag(values: ArrayView1<Complex<f64>>) -> Array2<Complex<f64>> {
+    let n = values.len();
+    let mut m = Array2::<Complex<f64>>::zeros((n, n));
+    for i in 0..n {
+        m[[i, i]] = values[i];
+    }
+    m
+}
+
+/// `Z0 = diag(z0)`, the port reference impedance matrix.
+fn z0_matrix(z0_col: ArrayView1<Complex<f64>>) -> Array2<Complex<f64>> {
+    diag(z0_col)
+}
+
+/// `G = diag(1/sqrt(Re z0))`.
+fn conductance_matrix(z0_col: ArrayView1<Complex<f64>>) -> Array2<Complex<f64>> {
+    let g = z0_col.mapv(|z| Complex::new(1. / z.re.sqrt(), 0.));
+    diag(g.view())
+}
+
+/// Inverts a square complex matrix by Gauss-Jordan elimination with partial
+/// pivoting. The networks this crate deals with are a handful of ports at
+/// most, so this is plenty fast without reaching for a full LU crate.
+pub(crate) fn invert(m: &Array2<Complex<f64>>) -> Array2<Complex<f64>> {
+    let n = m.nrows();
+    assert_eq!(n, m.ncols(), "matrix inversion requires a square matrix");
+
+    let mut aug = vec![vec![Complex::new(0., 0.); 2 * n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            aug[i][j] = m[[i, j]];
+        }
+        aug[i][n + i] = Complex::new(1., 0.);
+    }
+
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&a, &b| aug[a][col].norm().partial_cmp(&aug[b][col].norm()).unwrap())
+            .unwrap();
+        assert!(aug[pivot][col].norm() > 0., "matrix is singular");
+        aug.swap(col, pivot);
+
+        let pivot_val = aug[col][col];
+        for v in aug[col].iter_mut() {
+            *v /= pivot_val;
+        }
+        for row in 0..n {
+            if row == col {
+                continue;
+            }
+            let factor = aug[row][col];
+            if factor == Complex::new(0., 0.) {
+                continue;
+            }
+            let (lo, hi) = if row < col { (row, col) } else { (col, row) };
+            let (head, tail) = aug.split_at_mut(hi);
+            let (row_vec, col_vec) = if row < col {
+                (&mut head[lo], &tail[0])
+            } else {
+                (&mut tail[0], &head[lo])
+            };
+            for (v, c) in row_vec.iter_mut().zip(col_vec.iter()) {
+                *v -= factor * *c;
+            }
+        }
+    }
+
+    Array2::from_shape_fn((n, n), |(i, j)| aug[i][n + j])
+}
+
+impl Network {
+    /// Converts the stored S-parameters to Z-parameters at each frequency:
+    /// `Z = G^-1 (I-S)^-1 (S Z0 + Z0*) G`.
+    pub fn z(&self) -> Array3<Complex<f64>> {
+        let (nfreq, nports, _) = self.s.dim();
+        let identity = Array2::<Complex<f64>>::eye(nports);
+        let mut z = Array3::<Complex<f64>>::zeros((nfreq, nports, nports));
+        for k in 0..nfreq {
+            let s_k = self.s.slice(s![k, .., ..]).to_owned();
+            let z0_k = self.z0.column(k);
+            let g = conductance_matrix(z0_k);
+            let z0m = z0_matrix(z0_k);
+            let z0_conj = z0m.mapv(|v| v.conj());
+
+            let g_inv = invert(&g);
+            let one_minus_s_inv = invert(&(&identity - &s_k));
+            let z_k = g_inv.dot(&one_minus_s_inv).dot(&(s_k.dot(&z0m) + &z0_conj)).dot(&g);
+            z.slice_mut(s![k, .., ..]).assign(&z_k);
+        }
+        z
+    }
+
+    /// Converts the stored S-parameters to Y-parameters at each frequency,
+    /// via `Y = Z^-1`.
+    pub fn y(&self) -> Array3<Complex<f64>> {
+        let z = self.z();
+        let (nfreq, nports, _) = z.dim();
+        let mut y = Array3::<Complex<f64>>::zeros((nfreq, nports, nports));
+        for k in 0..nfreq {
+            let z_k = z.slice(s![k, .., ..]).to_owned();
+            y.slice_mut(s![k, .., ..]).assign(&invert(&z_k));
+        }
+        y
+    }
+
+    /// Converts the stored S-parameters to ABCD-parameters at each
+    /// frequency. Only defined for two-port networks.
+    pub fn abcd(&self) -> Array3<Complex<f64>> {
+        let z = self.z();
+        let (nfreq, nports, _) = z.dim();
+        assert_eq!(nports, 2, "ABCD parameters are only defined for two-port networks");
+
+        let mut abcd = Array3::<Complex<f64>>::zeros((nfreq, 2, 2));
+        for k in 0..nfreq {
+            let z11 = z[[k, 0, 0]];
+            let z12 = z[[k, 0, 1]];
+            let z21 = z[[k, 1, 0]];
+            let z22 = z[[k, 1, 1]];
+            let det_z = z11 * z22 - z12 * z21;
+
+            abcd[[k, 0, 0]] = z11 / z21;
+            abcd[[k, 0, 1]] = det_z / z21;
+            abcd[[k, 1, 0]] = Complex::new(1., 0.) / z21;
+            abcd[[k, 1, 1]] = z22 / z21;
+        }
+        abcd
+    }
+
+    /// Builds a `Network` from Z-parameters referenced to `z0`, by solving
+    /// the defining relation for S: `S = (G Z G^-1 - Z0*)(Z0 + G Z G^-1)^-1`.
+    pub fn from_z(f: Frequency, z: Array3<Complex<f64>>, z0: Array2<Complex<f64>>) -> Self {
+        let (nfreq, nports, _) = z.dim();
+        let mut s = Array3::<Complex<f64>>::zeros((nfreq, nports, nports));
+        for k in 0..nfreq {
+            let z_k = z.slice(s![k, .., ..]).to_owned();
+            let z0_k = z0.column(k);
+            let g = conductance_matrix(z0_k);
+            let z0m = z0_matrix(z0_k);
+            let z0_conj = z0m.mapv(|v| v.conj());
+
+            let g_inv = invert(&g);
+            let gzg_inv = g.dot(&z_k).dot(&g_inv);
+            let s_k = (&gzg_inv - &z0_conj).dot(&invert(&(&z0m + &gzg_inv)));
+            s.slice_mut(s![k, .., ..]).assign(&s_k);
+        }
+        Network::new(f, s, z0)
+    }
+
+    /// Builds a `Network` from Y-parameters referenced to `z0`, via
+    /// `Z = Y^-1` followed by [`Network::from_z`].
+    pub fn from_y(f: Frequency, y: Array3<Complex<f64>>, z0: Array2<Complex<f64>>) -> Self {
+        let (nfreq, nports, _) = y.dim();
+        let mut z = Array3::<Complex<f64>>::zeros((nfreq, nports, nports));
+        for k in 0..nfreq {
+            let y_k = y.slice(s![k, .., ..]).to_owned();
+            z.slice_mut(s![k, .., ..]).assign(&invert(&y_k));
+        }
+        Network::from_z(f, z, z0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::frequency::{FreqUnit, Frequency};
+    use ndarray::array;
+
+    fn approx_eq(a: &Array3<Complex<f64>>, b: &Array3<Complex<f64>>, tol: f64) -> bool {
+        a.iter().zip(b.iter()).all(|(x, y)| (x - y).norm() < tol)
+    }
+
+    #[test]
+    fn test_s_z_round_trip() {
+        let freq = Frequency::new(1., 1., Some(1), Some(FreqUnit::GHz));
+        let s = array![[
+            [Complex::new(0.1, 0.05), Complex::new(0.8, -0.1)],
+            [Complex::new(0.8, -0.1), Complex::new(0.2, 0.02)],
+        ]];
+        let z0 = Array::from_elem((2, 1), Complex::new(50., 0.));
+        let net = Network::new(freq.clone(), s.clone(), z0.clone());
+
+        let z = net.z();
+        let round_tripped = Network::from_z(freq, z, z0);
+
+        assert!(approx_eq(&round_tripped.s, &s, 1e-9));
+    }
+
+    #[test]
+    fn test_y_is_z_inverse() {
+        let freq = Frequency::new(1., 1., Some(1), Some(FreqUnit::GHz));
+        let s = array![[
+            [Complex::new(0.1, 0.05), Complex::new(0.8, -0.1)],
+            [Complex::new(0.8, -0.1), Complex::new(0.2, 0.02)],
+        ]];
+        let z0 = Array::from_elem((2, 1), Complex::new(50., 0.));
+        let net = Network::new(freq, s, z0);
+
+        let z = net.z();
+        let y = net.y();
+        let identity = Array2::<Complex<f64>>::eye(2);
+        let z0_slice: Array2<Complex<f64>> = z.slice(s![0, .., ..]).to_owned();
+        let y0_slice: Array2<Complex<f64>> = y.slice(s![0, .., ..]).to_owned();
+        let product = z0_slice.dot(&y0_slice);
+        assert!((product - identity).iter().all(|v| v.norm() < 1e-9));
+    }
+}
@@ -0,0 +1,97 @@
+//! Resampling a `Network` onto a new frequency grid, e.g. so two
+//! measurements taken on different sweeps can be cascaded or averaged.
+
+use ndarray::prelude::*;
+use num::complex::Complex;
+
+use crate::frequency::Frequency;
+
+use super::Network;
+
+/// Finds the pair of indices in `hz` (assumed sorted ascending) bracketing
+/// `target`, along with how far across that interval it falls in `[0, 1]`.
+fn bracket(hz: &Array1<f64>, target: f64) -> (usize, usize, f64) {
+    let n = hz.len();
+    for i in 0..n - 1 {
+        if target >= hz[i] && target <= hz[i + 1] {
+            let frac = if hz[i + 1] > hz[i] {
+                (target - hz[i]) / (hz[i + 1] - hz[i])
+            } else {
+                0.
+            };
+            return (i, i + 1, frac);
+        }
+    }
+    (n - 1, n - 1, 0.)
+}
+
+/// Linearly interpolates the real and imaginary parts independently.
+fn lerp(a: Complex<f64>, b: Complex<f64>, frac: f64) -> Complex<f64> {
+    a + (b - a) * frac
+}
+
+impl Network {
+    /// Resamples the S-parameters (and `z0`) onto `new_freq`, linearly
+    /// interpolating the real and imaginary parts of each entry across the
+    /// frequency axis. Every point of `new_freq` must fall within this
+    /// network's existing sweep.
+    pub fn interpolate(&self, new_freq: &Frequency) -> Network {
+        let old_hz = self.f.as_hz();
+        let new_hz = new_freq.as_hz();
+        let nports = self.s.dim().1;
+
+        let mut s = Array3::<Complex<f64>>::zeros((new_hz.len(), nports, nports));
+        let mut z0 = Array2::<Complex<f64>>::zeros((nports, new_hz.len()));
+
+        for (k, &f) in new_hz.iter().enumerate() {
+            assert!(
+                f >= old_hz[0] && f <= old_hz[old_hz.len() - 1],
+                "interpolation target frequency {} Hz is outside the network's sweep",
+                f
+            );
+            let (lo, hi, frac) = bracket(&old_hz, f);
+            for i in 0..nports {
+                for j in 0..nports {
+                    s[[k, i, j]] = lerp(self.s[[lo, i, j]], self.s[[hi, i, j]], frac);
+                }
+                z0[[i, k]] = lerp(self.z0[[i, lo]], self.z0[[i, hi]], frac);
+            }
+        }
+
+        Network::new(new_freq.clone(), s, z0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::frequency::{FreqUnit, Frequency};
+    use ndarray::array;
+
+    #[test]
+    fn test_interpolate_linear() {
+        let freq = Frequency::new(1., 3., Some(3), Some(FreqUnit::Hz));
+        let s = array![
+            [
+                [Complex::new(0., 0.), Complex::new(1., 0.)],
+                [Complex::new(1., 0.), Complex::new(0., 0.)],
+            ],
+            [
+                [Complex::new(0.2, 0.), Complex::new(0.8, 0.)],
+                [Complex::new(0.8, 0.), Complex::new(0.1, 0.)],
+            ],
+            [
+                [Complex::new(0.4, 0.), Complex::new(0.6, 0.)],
+                [Complex::new(0.6, 0.), Complex::new(0.2, 0.)],
+            ],
+        ];
+        let z0 = Array2::from_elem((2, 3), Complex::new(50., 0.));
+        let net = Network::new(freq, s, z0);
+
+        let new_freq = Frequency::new(2., 2., Some(1), Some(FreqUnit::Hz));
+        let interpolated = net.interpolate(&new_freq);
+
+        assert!((interpolated.s[[0, 0, 0]] - Complex::new(0.2, 0.)).norm() < 1e-9);
+        assert!((interpolated.s[[0, 0, 1]] - Complex::new(0.8, 0.)).norm() < 1e-9);
+    }
+}
@@ -0,0 +1,234 @@
+mod cascade;
+mod convert;
+mod interpolate;
+mod renormalize;
+
+pub use cascade::connect;
+pub use renormalize::ZNew;
+
+use std::path::Path;
+
+use ndarray::prelude::*;
+use num::complex::Complex;
+
+use crate::frequency::{FreqUnit, Frequency};
+use crate::io::touchstone::{NoisePoint, ParamFormat, ParamType, Touchstone};
+use crate::result::ParseError;
+
+#[derive(Debug, PartialEq)]
+pub struct Network {
+    f: Frequency,
+    s: Array3<Complex<f64>>,
+    z0: Array2<Complex<f64>>,
+    noise: Vec<NoisePoint>,
+}
+
+impl Network {
+    pub fn new(f: Frequency, s: Array3<Complex<f64>>, z0: Array2<Complex<f64>>) -> Self {
+        Network {
+            f,
+            s,
+            z0,
+            noise: vec![],
+        }
+    }
+
+    /// Attaches noise-parameter data parsed from a Touchstone file.
+    pub(crate) fn with_noise(mut self, noise: Vec<NoisePoint>) -> Self {
+        self.noise = noise;
+        self
+    }
+
+    /// The minimum noise figure, in dB, at each parsed noise-data frequency.
+    pub fn noise_figure_min(&self) -> Vec<f64> {
+        self.noise.iter().map(|n| n.fmin_db).collect()
+    }
+
+    /// The optimum source reflection coefficient at each parsed
+    /// noise-data frequency.
+    pub fn gamma_opt(&self) -> Vec<Complex<f64>> {
+        self.noise.iter().map(|n| n.gamma_opt).collect()
+    }
+
+    pub fn from_snp(file: &Path) -> Result<Self, crate::result::ParseError> {
+        let touchstone = Touchstone::new(file)?;
+        let f = Frequency::from_raw(&touchstone.freqs(), touchstone.unit());
+        let nfreq = touchstone.freqs().len();
+        let params = touchstone.s_params();
+        let nports = params.dim().1;
+
+        let z0 = match touchstone.reference() {
+            Some(reference) => {
+                let mut z0 = Array2::<Complex<f64>>::zeros((nports, nfreq));
+                for (port, r) in reference.iter().enumerate() {
+                    z0.row_mut(port).fill(Complex::new(*r, 0.));
+                }
+                z0
+            }
+            None => Array::from_elem((nports, nfreq), Complex::new(touchstone.resistance(), 0.)),
+        };
+
+        let network = match touchstone.param_type() {
+            ParamType::S => Network::new(f, params, z0),
+            ParamType::Z => Network::from_z(f, params, z0),
+            ParamType::Y => Network::from_y(f, params, z0),
+            // Hybrid/inverse-hybrid parameters have no conversion implemented yet.
+            ParamType::G | ParamType::H => return Err(ParseError),
+        };
+        Ok(network.with_noise(touchstone.noise()))
+    }
+
+    /// Serializes this network back out to a spec-compliant sNp file. Using
+    /// `RealImag`/`Hz` keeps `from_snp` -> `to_snp` lossless. Every port's
+    /// reference impedance must be real and constant across frequency;
+    /// if the ports don't all share the same value, a `[Reference]` block
+    /// carrying the full per-port list is emitted alongside the `R` option.
+    pub fn to_snp(&self, path: &Path) -> Result<(), ParseError> {
+        let freqs = self.f.as_hz().to_vec();
+        let (nports, nfreq) = self.z0.dim();
+        let mut per_port = Vec::with_capacity(nports);
+        for port in 0..nports {
+            let z = self.z0[[port, 0]];
+            if z.im != 0. || (1..nfreq).any(|k| self.z0[[port, k]] != z) {
+                return Err(ParseError);
+            }
+            per_port.push(z.re);
+        }
+
+        let resistance = per_port[0];
+        let reference = if per_port.iter().all(|r| (*r - resistance).abs() < f64::EPSILON) {
+            None
+        } else {
+            Some(per_port)
+        };
+
+        let touchstone = Touchstone::from_network(freqs, self.s.clone(), resistance, reference);
+        touchstone.write(path, ParamFormat::RealImag, FreqUnit::Hz)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::frequency::{FreqUnit, Frequency};
+    use ndarray::array;
+
+    #[test]
+    fn test_instantiation() {
+        let freq = Frequency::new(1., 3., Some(3), Some(FreqUnit::GHz));
+        let one_c = num::Complex::new(1., 0.);
+        let s = Array::from_elem((1, 1, 3), one_c);
+        let z0 = Array::from_elem((1, 3), one_c);
+        let net = Network::new(freq, s, z0);
+    }
+
+    #[test]
+    fn test_to_snp_from_snp_round_trip() {
+        let freq = Frequency::new(1e9, 1e9, Some(1), Some(FreqUnit::Hz));
+        let s = array![[
+            [Complex::new(0.1, 0.05), Complex::new(0.3, -0.2)],
+            [Complex::new(0.6, 0.1), Complex::new(0.2, 0.02)],
+        ]];
+        let z0 = Array::from_elem((2, 1), Complex::new(50., 0.));
+        let net = Network::new(freq, s.clone(), z0);
+
+        let path = std::env::temp_dir().join("scirust_rf_round_trip_test.s2p");
+        net.to_snp(&path).unwrap();
+        let read_back = Network::from_snp(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!((read_back.f.as_hz()[0] - 1e9).abs() < 1.);
+        assert!(read_back
+            .s
+            .iter()
+            .zip(s.iter())
+            .all(|(a, b)| (a - b).norm() < 1e-6));
+    }
+
+    #[test]
+    fn test_to_snp_from_snp_round_trip_five_port() {
+        let freq = Frequency::new(1e9, 1e9, Some(1), Some(FreqUnit::Hz));
+        let nports = 5;
+        let mut s = Array3::<Complex<f64>>::zeros((1, nports, nports));
+        for i in 0..nports {
+            for j in 0..nports {
+                s[[0, i, j]] = Complex::new(0.01 * (i * nports + j) as f64, 0.02 * (i * nports + j) as f64);
+            }
+        }
+        let z0 = Array::from_elem((nports, 1), Complex::new(50., 0.));
+        let net = Network::new(freq, s.clone(), z0);
+
+        let path = std::env::temp_dir().join("scirust_rf_round_trip_test_5port.s5p");
+        net.to_snp(&path).unwrap();
+        let read_back = Network::from_snp(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(read_back
+            .s
+            .iter()
+            .zip(s.iter())
+            .all(|(a, b)| (a - b).norm() < 1e-6));
+    }
+
+    #[test]
+    fn test_to_snp_writes_reference_block_for_nonuniform_z0() {
+        let freq = Frequency::new(1e9, 1e9, Some(1), Some(FreqUnit::Hz));
+        let s = array![[
+            [Complex::new(0.1, 0.05), Complex::new(0.3, -0.2)],
+            [Complex::new(0.6, 0.1), Complex::new(0.2, 0.02)],
+        ]];
+        let z0 = array![[Complex::new(50., 0.)], [Complex::new(75., 0.)]];
+        let net = Network::new(freq, s.clone(), z0.clone());
+
+        let path = std::env::temp_dir().join("scirust_rf_reference_block_test.s2p");
+        net.to_snp(&path).unwrap();
+        let read_back = Network::from_snp(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(read_back.z0, z0);
+        assert!(read_back
+            .s
+            .iter()
+            .zip(s.iter())
+            .all(|(a, b)| (a - b).norm() < 1e-6));
+    }
+
+    #[test]
+    fn test_to_snp_rejects_complex_port_impedance() {
+        let freq = Frequency::new(1e9, 1e9, Some(1), Some(FreqUnit::Hz));
+        let s = array![[
+            [Complex::new(0.1, 0.05), Complex::new(0.3, -0.2)],
+            [Complex::new(0.6, 0.1), Complex::new(0.2, 0.02)],
+        ]];
+        let z0 = array![[Complex::new(50., 5.)], [Complex::new(50., 0.)]];
+        let net = Network::new(freq, s, z0);
+
+        let path = std::env::temp_dir().join("scirust_rf_reference_reject_test.s2p");
+        assert!(net.to_snp(&path).is_err());
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_from_snp_scales_non_hz_unit() {
+        let path = std::env::temp_dir().join("scirust_rf_unit_scaling_test.s2p");
+        let contents = "\
+! unit scaling test
+# GHz S MA R 50
+1.0 0.5 30.0 0.8 -10.0 0.8 -10.0 0.3 45.0
+3.0 0.6 20.0 0.7 -5.0 0.7 -5.0 0.4 35.0
+[End]
+";
+        std::fs::write(&path, contents).unwrap();
+        let net = Network::from_snp(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!((net.f.as_hz()[0] - 1e9).abs() < 1.);
+        assert!((net.f.as_hz()[1] - 3e9).abs() < 1.);
+
+        // Interpolating against an absolute-Hz target within the sweep must
+        // not panic now that `as_hz()` is actually in Hz.
+        let new_freq = Frequency::new(2e9, 2e9, Some(1), Some(FreqUnit::Hz));
+        let interpolated = net.interpolate(&new_freq);
+        assert_eq!(interpolated.s.dim().0, 1);
+    }
+}
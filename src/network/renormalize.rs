@@ -0,0 +1,128 @@
+//! Renormalizing a `Network` to a new port reference impedance, per the
+//! general power-wave renormalization of Marks & Williams: with
+//! `Gamma = diag(Gamma_i)`, `Gamma_i = (z_new_i - z_old_i)/(z_new_i + z_old_i)`,
+//! and `A = diag(A_i)`, `A_i = (1 - Gamma_i*)/|1 - Gamma_i| * sqrt(1 - |Gamma_i|^2)`,
+//! the S-matrix in the new reference is
+//! `S_new = A^-1 (S - Gamma*) (I - Gamma S)^-1 A*`.
+
+use ndarray::prelude::*;
+use num::complex::Complex;
+
+use super::convert::{diag, invert};
+use super::Network;
+
+/// The new reference impedance to renormalize a `Network` to: either a
+/// single value broadcast to every port, or one value per port.
+pub enum ZNew {
+    Scalar(Complex<f64>),
+    PerPort(Vec<Complex<f64>>),
+}
+
+impl ZNew {
+    fn at_port(&self, port: usize) -> Complex<f64> {
+        match self {
+            ZNew::Scalar(z) => *z,
+            ZNew::PerPort(ports) => ports[port],
+        }
+    }
+}
+
+impl From<f64> for ZNew {
+    fn from(z: f64) -> Self {
+        ZNew::Scalar(Complex::new(z, 0.))
+    }
+}
+
+impl From<Complex<f64>> for ZNew {
+    fn from(z: Complex<f64>) -> Self {
+        ZNew::Scalar(z)
+    }
+}
+
+impl From<Vec<f64>> for ZNew {
+    fn from(z: Vec<f64>) -> Self {
+        ZNew::PerPort(z.into_iter().map(|r| Complex::new(r, 0.)).collect())
+    }
+}
+
+impl From<Vec<Complex<f64>>> for ZNew {
+    fn from(z: Vec<Complex<f64>>) -> Self {
+        ZNew::PerPort(z)
+    }
+}
+
+impl Network {
+    /// Renormalizes this network's S-parameters and `z0` to `z_new`, a
+    /// single impedance broadcast to every port or a per-port vector. A
+    /// frequency point at which every port is already at its target
+    /// impedance is left unchanged; otherwise the whole S-matrix at that
+    /// point is recomputed, including the entries of ports whose impedance
+    /// does not change.
+    pub fn renormalize(&mut self, z_new: impl Into<ZNew>) {
+        let z_new = z_new.into();
+        let (nfreq, nports, _) = self.s.dim();
+        let identity = Array2::<Complex<f64>>::eye(nports);
+        let one = Complex::new(1., 0.);
+
+        for k in 0..nfreq {
+            if (0..nports).all(|i| z_new.at_port(i) == self.z0[[i, k]]) {
+                continue;
+            }
+
+            let mut gamma = Array1::<Complex<f64>>::zeros(nports);
+            let mut a = Array1::<Complex<f64>>::zeros(nports);
+            for i in 0..nports {
+                let z_new_i = z_new.at_port(i);
+                let z_old_i = self.z0[[i, k]];
+                let r = (z_new_i - z_old_i) / (z_new_i + z_old_i);
+                gamma[i] = r;
+                a[i] = (one - r.conj()) / (one - r).norm() * (one - r * r.conj()).sqrt();
+            }
+
+            let gamma_diag = diag(gamma.view());
+            let gamma_conj_diag = gamma_diag.mapv(|v| v.conj());
+            let a_diag = diag(a.view());
+            let a_conj_diag = a_diag.mapv(|v| v.conj());
+
+            let s_k = self.s.slice(s![k, .., ..]).to_owned();
+            let inner = invert(&(&identity - gamma_diag.dot(&s_k)));
+            let s_new_k = invert(&a_diag)
+                .dot(&(s_k - &gamma_conj_diag))
+                .dot(&inner)
+                .dot(&a_conj_diag);
+            self.s.slice_mut(s![k, .., ..]).assign(&s_new_k);
+
+            for i in 0..nports {
+                self.z0[[i, k]] = z_new.at_port(i);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::frequency::{FreqUnit, Frequency};
+    use ndarray::array;
+
+    #[test]
+    fn test_renormalize_round_trip() {
+        let freq = Frequency::new(1., 1., Some(1), Some(FreqUnit::GHz));
+        let s = array![[
+            [Complex::new(0.1, 0.05), Complex::new(0.3, -0.1)],
+            [Complex::new(0.6, 0.2), Complex::new(0.2, 0.02)],
+        ]];
+        let z0 = Array2::from_elem((2, 1), Complex::new(50., 0.));
+        let mut net = Network::new(freq, s.clone(), z0);
+
+        net.renormalize(75.);
+        net.renormalize(vec![50., 50.]);
+
+        for i in 0..2 {
+            for j in 0..2 {
+                let diff = (net.s[[0, i, j]] - s[[0, i, j]]).norm();
+                assert!(diff < 1e-9, "mismatch at ({}, {}): {}", i, j, diff);
+            }
+        }
+    }
+}
@@ -26,7 +26,7 @@ impl Default for TouchstoneVersion {
 }
 
 #[derive(PartialEq, Debug)]
-enum ParamType {
+pub(crate) enum ParamType {
     S,
     Y,
     Z,
@@ -50,8 +50,8 @@ impl FromStr for ParamType {
     }
 }
 
-#[derive(PartialEq, Debug)]
-enum ParamFormat {
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(crate) enum ParamFormat {
     DBAngle,
     MagAngle,
     RealImag,
@@ -90,6 +90,17 @@ impl Default for TouchstoneOptions {
     }
 }
 
+/// One row of the trailing Touchstone noise-parameter block: the noise
+/// figure under optimum source match, the source reflection coefficient
+/// that achieves it, and the normalized equivalent noise resistance.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NoisePoint {
+    pub(crate) freq: f64,
+    pub(crate) fmin_db: f64,
+    pub(crate) gamma_opt: Complex<f64>,
+    pub(crate) rn: f64,
+}
+
 #[derive(Default)]
 pub struct Touchstone {
     filename: String,
@@ -103,7 +114,7 @@ pub struct Touchstone {
     options: TouchstoneOptions,
     s_params: CxArray3,
     rank: usize,
-    noise: Option<CxArray3>,
+    noise: Vec<NoisePoint>,
 }
 
 impl Touchstone {
@@ -115,6 +126,26 @@ impl Touchstone {
         self.s_params.clone()
     }
 
+    pub(crate) fn param_type(&self) -> &ParamType {
+        &self.options.param_type
+    }
+
+    pub(crate) fn resistance(&self) -> f64 {
+        self.options.resistance
+    }
+
+    pub(crate) fn reference(&self) -> Option<Vec<f64>> {
+        self.reference.clone()
+    }
+
+    pub(crate) fn unit(&self) -> FreqUnit {
+        self.options.unit
+    }
+
+    pub(crate) fn noise(&self) -> Vec<NoisePoint> {
+        self.noise.clone()
+    }
+
     pub fn new(path: &Path) -> Result<Self, ParseError> {
         let mut touchstone = Touchstone::default();
 
@@ -140,6 +171,9 @@ impl Touchstone {
 
         // Main parse loop
         let mut options_read = false;
+        let mut in_noise_section = false;
+        let mut last_network_freq: Option<f64> = None;
+        let mut pairs_collected_for_current_freq: usize = 0;
         let file = File::open(path).unwrap();
         let mut buf_reader = BufReader::new(file);
         let mut line_buf = String::new();
@@ -203,6 +237,11 @@ impl Touchstone {
                 // According to the spec, this just explicitly marks the beginning of network data.
                 // It seems we can just ignore it.
                 continue;
+            } else if line.starts_with("[noise data]") {
+                // v2 files mark the noise block explicitly; v1 files don't, and are
+                // instead detected by the frequency column restarting (see below).
+                in_noise_section = true;
+                continue;
             } else if line.starts_with("[end]") {
                 break;
             } else if line.starts_with('#') {
@@ -213,18 +252,48 @@ impl Touchstone {
                     .split_whitespace()
                     .map(|v| v.parse::<f64>().unwrap())
                     .collect();
-                // If the line starts with a frequency or if all data is contained in one line
-                if chunked.len() == (touchstone.rank * 2) + 1
-                    || chunked.len() == 2 * num::pow(touchstone.rank, 2) + 1
-                {
-                    touchstone.freqs.push(chunked[0]);
-                    chunked.remove(0);
+                // A line carries a leading frequency column exactly when the previous
+                // frequency's full `rank*rank` pairs have all been collected. This is
+                // line-count independent, so it holds whether a matrix row fits on one
+                // line or, per the spec's >4-pairs-per-row wrapping rule, is split
+                // across several continuation lines that carry only further pairs.
+                let starts_new_block = pairs_collected_for_current_freq == 0;
+                // v1 noise rows are always freq, Fmin(dB), |Gamma_opt|, angle(deg), Rn/Z0 --
+                // exactly 5 columns, and (like S-data) only ever begin a fresh block.
+                let is_noise_shaped_row = starts_new_block && chunked.len() == 5;
+
+                if !in_noise_section && touchstone.version == TouchstoneVersion::One && is_noise_shaped_row {
+                    if let Some(last) = last_network_freq {
+                        if chunked[0] < last {
+                            in_noise_section = true;
+                        }
+                    }
+                }
+
+                if in_noise_section {
+                    // v1/v2 noise rows are always freq, Fmin(dB), |Gamma_opt|, angle(deg), Rn/Z0
+                    touchstone.noise.push(NoisePoint {
+                        freq: chunked[0],
+                        fmin_db: chunked[1],
+                        gamma_opt: to_complex(chunked[2], chunked[3], &ParamFormat::MagAngle),
+                        rn: chunked[4],
+                    });
+                } else {
+                    if starts_new_block {
+                        last_network_freq = Some(chunked[0]);
+                        touchstone.freqs.push(chunked[0]);
+                        chunked.remove(0);
+                    }
+                    let pairs_iter = chunked.chunks(2);
+                    let mut temp: Vec<Complex<f64>> = pairs_iter
+                        .map(|pair| to_complex(pair[0], pair[1], &touchstone.options.param_format))
+                        .collect();
+                    pairs_collected_for_current_freq += temp.len();
+                    if pairs_collected_for_current_freq >= touchstone.rank * touchstone.rank {
+                        pairs_collected_for_current_freq = 0;
+                    }
+                    temp_s_params.append(&mut temp);
                 }
-                let pairs_iter = chunked.chunks(2);
-                let mut temp: Vec<Complex<f64>> = pairs_iter
-                    .map(|pair| Complex::new(pair[0], pair[1]))
-                    .collect();
-                temp_s_params.append(&mut temp);
             }
         }
         touchstone.s_params = match Array::from_shape_vec(
@@ -237,8 +306,159 @@ impl Touchstone {
             }
             _ => return Err(ParseError),
         };
+
+        if touchstone.rank == 2 {
+            // The spec lists two-port data transposed (S11 S21 S12 S22) rather
+            // than row-major, so a plain reshape leaves S12/S21 swapped.
+            // Put them back in their physical s[i][j] = S_ij positions.
+            for k in 0..touchstone.s_params.dim().0 {
+                let s12 = touchstone.s_params[[k, 0, 1]];
+                touchstone.s_params[[k, 0, 1]] = touchstone.s_params[[k, 1, 0]];
+                touchstone.s_params[[k, 1, 0]] = s12;
+            }
+        }
+
         Ok(touchstone)
     }
+
+    /// Builds a `Touchstone` ready to be written out from an S-parameter
+    /// array and a reference resistance, as used by `Network::to_snp`.
+    /// `reference` carries the full per-port resistance list when ports
+    /// don't all share `resistance`, so `write` can emit a `[Reference]`
+    /// block in addition to the single-value `R` option.
+    pub(crate) fn from_network(
+        freqs: Vec<f64>,
+        s_params: CxArray3,
+        resistance: f64,
+        reference: Option<Vec<f64>>,
+    ) -> Self {
+        let rank = s_params.dim().1;
+        Touchstone {
+            rank,
+            freqs,
+            s_params,
+            reference,
+            options: TouchstoneOptions {
+                resistance,
+                // `freqs` is already in Hz (see `Network::to_snp`), so `write`
+                // must not rescale it again when converting to the output unit.
+                unit: FreqUnit::Hz,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Writes a spec-compliant sNp file: an `!` comment header, the `#`
+    /// options line (plus a `[Reference]` block when per-port resistances
+    /// aren't uniform), then one row per frequency with the frequency
+    /// column followed by the S-parameter pairs. Two-port files use the
+    /// spec's transposed column order (`S11 S21 S12 S22`); larger networks
+    /// are written one matrix row at a time, wrapped at four pairs per line.
+    pub fn write(&self, path: &Path, format: ParamFormat, unit: FreqUnit) -> Result<(), ParseError> {
+        let mut file = File::create(path).map_err(|_| ParseError)?;
+        writeln!(file, "! Touchstone file written by scirust-rf").map_err(|_| ParseError)?;
+
+        let format_code = match format {
+            ParamFormat::RealImag => "RI",
+            ParamFormat::MagAngle => "MA",
+            ParamFormat::DBAngle => "DB",
+        };
+        let unit_code = match unit {
+            FreqUnit::Hz => "Hz",
+            FreqUnit::KHz => "kHz",
+            FreqUnit::MHz => "MHz",
+            FreqUnit::GHz => "GHz",
+            FreqUnit::THz => "THz",
+        };
+        writeln!(
+            file,
+            "# {} S {} R {}",
+            unit_code, format_code, self.options.resistance
+        )
+        .map_err(|_| ParseError)?;
+
+        if let Some(reference) = &self.reference {
+            writeln!(file, "[Reference]").map_err(|_| ParseError)?;
+            let line = reference.iter().map(|r| r.to_string()).collect::<Vec<_>>().join(" ");
+            writeln!(file, "{}", line).map_err(|_| ParseError)?;
+        }
+
+        let (nfreq, rank, _) = self.s_params.dim();
+        for k in 0..nfreq {
+            let freq_out = (self.options.unit * self.freqs[k]) / (unit * 1.);
+            if rank == 2 {
+                let pairs = [
+                    self.s_params[[k, 0, 0]],
+                    self.s_params[[k, 1, 0]],
+                    self.s_params[[k, 0, 1]],
+                    self.s_params[[k, 1, 1]],
+                ];
+                write_row(&mut file, Some(freq_out), &pairs, format)?;
+            } else {
+                for i in 0..rank {
+                    let row: Vec<Complex<f64>> = (0..rank).map(|j| self.s_params[[k, i, j]]).collect();
+                    let freq_col = if i == 0 { Some(freq_out) } else { None };
+                    write_row(&mut file, freq_col, &row, format)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes one Touchstone matrix row, wrapped at four pairs per line per the
+/// spec: the optional leading frequency column goes on the first line only,
+/// and any pairs past the fourth spill onto frequency-less continuation
+/// lines.
+fn write_row(
+    file: &mut File,
+    freq: Option<f64>,
+    values: &[Complex<f64>],
+    format: ParamFormat,
+) -> Result<(), ParseError> {
+    use std::fmt::Write as _;
+
+    const PAIRS_PER_LINE: usize = 4;
+    for (chunk_idx, chunk) in values.chunks(PAIRS_PER_LINE).enumerate() {
+        let mut line = match (chunk_idx, freq) {
+            (0, Some(f)) => format!("{} ", f),
+            _ => String::from("    "),
+        };
+        for v in chunk {
+            let (a, b) = from_complex(*v, format);
+            write!(line, "{} {} ", a, b).map_err(|_| ParseError)?;
+        }
+        writeln!(file, "{}", line.trim_end()).map_err(|_| ParseError)?;
+    }
+    Ok(())
+}
+
+/// Converts a raw value pair read from a Touchstone data row into a
+/// rectangular `Complex` according to the file's declared `param_format`.
+fn to_complex(first: f64, second: f64, format: &ParamFormat) -> Complex<f64> {
+    match format {
+        ParamFormat::RealImag => Complex::new(first, second),
+        ParamFormat::MagAngle => {
+            let theta = second.to_radians();
+            Complex::new(first * theta.cos(), first * theta.sin())
+        }
+        ParamFormat::DBAngle => {
+            let mag = 10f64.powf(first / 20.);
+            let theta = second.to_radians();
+            Complex::new(mag * theta.cos(), mag * theta.sin())
+        }
+    }
+}
+
+/// Inverts `to_complex`: recovers the raw value pair that a Touchstone
+/// writer would print for `z` in the given `param_format`.
+fn from_complex(z: Complex<f64>, format: ParamFormat) -> (f64, f64) {
+    match format {
+        ParamFormat::RealImag => (z.re, z.im),
+        ParamFormat::MagAngle => (z.norm(), z.arg().to_degrees()),
+        ParamFormat::DBAngle => (20. * z.norm().log10(), z.arg().to_degrees()),
+    }
 }
 
 fn parse_options_line(line: &str, options: &mut TouchstoneOptions) -> Result<(), ParseError> {
@@ -340,6 +560,90 @@ mod tests {
         assert_eq!(touchstone.reference, Some(vec![15.063, 15.063, 15.063, 15.063, 15.063, 15.063]));
     }
 
+    #[test]
+    fn test_noise_data_parsing() {
+        let path = std::env::temp_dir().join("scirust_rf_noise_test.s2p");
+        let contents = "\
+! synthetic noise test file
+# GHz S MA R 50
+1.0 0.5 30.0 0.8 -10.0 0.8 -10.0 0.3 45.0
+[Noise Data]
+1.0 0.5 0.6 60.0 20.0
+[End]
+";
+        std::fs::write(&path, contents).unwrap();
+        let touchstone = Touchstone::new(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let noise = touchstone.noise();
+        assert_eq!(noise.len(), 1);
+        assert_eq!(noise[0].freq, 1.0);
+        assert_eq!(noise[0].fmin_db, 0.5);
+        assert_eq!(noise[0].rn, 20.0);
+        assert!((noise[0].gamma_opt - to_complex(0.6, 60.0, &ParamFormat::MagAngle)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn test_v1_noise_data_parsing_multiport() {
+        // A 3-port v1 file has no `[Noise Data]` tag; the noise block is
+        // instead recognized because its frequency column restarts below
+        // the last S-data frequency (per the v1 convention).
+        let path = std::env::temp_dir().join("scirust_rf_noise_v1_3port_test.s3p");
+        let contents = "\
+! synthetic v1 noise test file, 3 ports
+# GHz S MA R 50
+1.0 0.5 30.0 0.1 0.0 0.1 0.0 0.1 0.0 0.8 -10.0 0.1 0.0 0.1 0.0 0.1 0.0 0.3 45.0
+2.0 0.5 30.0 0.1 0.0 0.1 0.0 0.1 0.0 0.8 -10.0 0.1 0.0 0.1 0.0 0.1 0.0 0.3 45.0
+1.0 0.5 0.6 60.0 20.0
+2.0 0.4 0.5 50.0 15.0
+";
+        std::fs::write(&path, contents).unwrap();
+        let touchstone = Touchstone::new(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(touchstone.s_params.dim(), (2, 3, 3));
+        let noise = touchstone.noise();
+        assert_eq!(noise.len(), 2);
+        assert_eq!(noise[0].freq, 1.0);
+        assert_eq!(noise[0].fmin_db, 0.5);
+        assert_eq!(noise[0].rn, 20.0);
+        assert!((noise[0].gamma_opt - to_complex(0.6, 60.0, &ParamFormat::MagAngle)).norm() < 1e-9);
+        assert_eq!(noise[1].freq, 2.0);
+        assert_eq!(noise[1].fmin_db, 0.4);
+        assert_eq!(noise[1].rn, 15.0);
+    }
+
+    #[test]
+    fn test_five_port_wraps_rows_at_four_pairs_per_line() {
+        // Spec-compliant wrapping for a rank > 4 network: each matrix row's
+        // 5 pairs spill onto a frequency-less continuation line after the
+        // fourth pair, independent of this crate's own writer.
+        let path = std::env::temp_dir().join("scirust_rf_wrapped_5port_test.s5p");
+        let contents = "\
+! genuinely wrapped 5-port file
+# GHz S RI R 50
+1.0 0.00 0.00 0.01 0.02 0.02 0.04 0.03 0.06
+    0.04 0.08
+0.05 0.10 0.06 0.12 0.07 0.14 0.08 0.16
+    0.09 0.18
+0.10 0.20 0.11 0.22 0.12 0.24 0.13 0.26
+    0.14 0.28
+0.15 0.30 0.16 0.32 0.17 0.34 0.18 0.36
+    0.19 0.38
+0.20 0.40 0.21 0.42 0.22 0.44 0.23 0.46
+    0.24 0.48
+[End]
+";
+        std::fs::write(&path, contents).unwrap();
+        let touchstone = Touchstone::new(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(touchstone.s_params.dim(), (1, 5, 5));
+        assert_eq!(touchstone.s_params[[0, 0, 0]], Complex::new(0.00, 0.00));
+        assert_eq!(touchstone.s_params[[0, 1, 0]], Complex::new(0.05, 0.10));
+        assert_eq!(touchstone.s_params[[0, 4, 4]], Complex::new(0.24, 0.48));
+    }
+
     #[test]
     fn test_simple_s2p() {
         let path = std::path::PathBuf::from("tests/ntwk_arbitrary_frequency.s2p");